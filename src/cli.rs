@@ -22,16 +22,21 @@ use havsalt_clap_styles::STYLES;
 #[derive(Parser, Debug)]
 #[command(name = "here", version, styles = STYLES)]
 pub struct Cli {
-    /// Additional path segment or program name used for searching
+    /// Additional path segment, program name, or search root
     ///
     /// Default mode: If not present, uses path of `current working directory`
     ///
     /// Segment mode: Treats argument as a path segment,
     /// that will be appended to the path of current working directory
     ///
-    /// Search mode: Searches for the binary/program,
+    /// Program search mode: Searches for the binary/program,
     /// and uses that path instead of current working directory.
     /// Requires: `-w/--from-where`
+    ///
+    /// File search mode: Treats argument as the directory to walk,
+    /// appended to the current working directory, while `-s/--search`
+    /// supplies the pattern to match entries against.
+    /// Requires: `-s/--search`
     #[arg(value_name = "PATH SEGMENT / PROGRAM SEARCH")]
     pub path_segment_or_program_search: Option<String>,
 
@@ -42,13 +47,15 @@ pub struct Cli {
     #[arg(short = 'f', long = "folder")]
     pub folder_component: bool,
 
-    /// Use `where` command to search
+    /// Search `PATH` for a matching binary/script
     ///
-    /// On Windows, the `where` command will be called in a subprocess.
+    /// Walks every directory in the `PATH` environment variable,
+    /// looking for an executable matching the given name exactly.
+    /// Pass `--glob` or `--regex` to instead match the file stem of
+    /// every candidate executable against a pattern, discovering every
+    /// variant installed (e.g. `here -w 'python*' --glob`).
     /// The result is the path to that binary/script
     ///
-    /// Todo: On Linux, use coresponding command to `where`
-    ///
     /// If multiple results are found,
     /// a prompt will be used to select which path to use.
     /// This can be skipped by supplying `--select-first`,
@@ -58,17 +65,94 @@ pub struct Cli {
     #[arg(
         short,
         long = "from-where",
-        requires = "path_segment_or_program_search"
+        requires = "path_segment_or_program_search",
+        conflicts_with = "search_pattern"
     )]
     pub where_search: bool,
 
+    /// Recursively search for a file/folder matching PATTERN
+    ///
+    /// Walks the directory tree rooted at the current working directory,
+    /// or the path segment given as the positional argument, looking for
+    /// an entry whose name matches PATTERN as a substring, or as a regex
+    /// with `--regex`
+    ///
+    /// Honors `.gitignore`/`.ignore` and skips hidden entries by default.
+    /// Use `--hidden`/`--no-ignore` to change that, and `--max-depth`
+    /// to bound how deep the walk goes
+    ///
+    /// If multiple results are found,
+    /// a prompt will be used to select which path to use.
+    /// This can be skipped by supplying `--select-first`,
+    /// to select the first option
+    #[arg(short = 's', long = "search", value_name = "PATTERN")]
+    pub search_pattern: Option<String>,
+
+    /// Match the `--search` pattern against the whole relative path
+    ///
+    /// Requires: `-s/--search`
+    #[arg(long, requires = "search_pattern")]
+    pub full_path: bool,
+
+    /// Include hidden entries in `--search`
+    ///
+    /// Requires: `-s/--search`
+    #[arg(long, requires = "search_pattern")]
+    pub hidden: bool,
+
+    /// Do not respect `.gitignore`/`.ignore` files in `--search`
+    ///
+    /// Requires: `-s/--search`
+    #[arg(long = "no-ignore", requires = "search_pattern")]
+    pub no_ignore: bool,
+
+    /// Limit how many directories deep `--search` descends
+    ///
+    /// Requires: `-s/--search`
+    #[arg(long = "max-depth", value_name = "N", requires = "search_pattern")]
+    pub max_depth: Option<usize>,
+
+    /// Treat the pattern as a regex, instead of a substring/exact match
+    ///
+    /// For `-s/--search`, matches against the entry name (or relative path
+    /// with `--full-path`) as a substring by default. For `-w/--from-where`,
+    /// matches the file stem of each candidate executable on `PATH`
+    ///
+    /// Requires: `-s/--search` or `-w/--from-where`
+    #[arg(long, conflicts_with = "glob")]
+    pub regex: bool,
+
+    /// Treat the `-w/--from-where` program name as a glob pattern,
+    /// matching the file stem of each candidate executable on `PATH`
+    ///
+    /// Requires: `-w/--from-where`
+    #[arg(long, requires = "where_search", conflicts_with = "regex")]
+    pub glob: bool,
+
     /// Set current working directory to result
     ///
     /// This is done by scheduling keyboard events
     /// that will write to the terminal after program execution
-    #[arg(short = 'd', long)]
+    #[arg(short = 'd', long, conflicts_with = "exec_command")]
     pub change_directory: bool,
 
+    /// Execute a command using the resolved path
+    ///
+    /// Supports placeholder tokens: `{}` the full path, `{/}` file name,
+    /// `{//}` parent directory, `{.}` path with extension removed.
+    /// A command with no placeholder has `{}` appended implicitly
+    ///
+    /// Runs after all path manipulations (`--folder`, `--resolve-symlink`),
+    /// so the command sees the final path
+    #[arg(
+        short = 'x',
+        long = "exec",
+        value_name = "CMD",
+        num_args = 1..,
+        allow_hyphen_values = true
+    )]
+    pub exec_command: Option<Vec<String>>,
+
     /// Escape backslashes
     ///
     /// "\\" -> "\\\\"
@@ -102,6 +186,18 @@ pub struct Cli {
     #[arg(short = 'c', long)]
     pub no_color: bool,
 
+    /// Colorize path components by file type, using `LS_COLORS`
+    ///
+    /// Styles each path component the way `ls`/`fd` would:
+    /// the final component is colored by its file extension,
+    /// falling back to its file type, and every parent directory uses the `di` rule
+    ///
+    /// Falls back to a bundled default palette if `LS_COLORS` is unset
+    ///
+    /// Fully suppressed by `-c/--no-color`
+    #[arg(long = "ls-colors")]
+    pub ls_colors: bool,
+
     /// Force posix style path
     ///
     /// Replaces all backslashes with forwardslashes
@@ -116,8 +212,8 @@ pub struct Cli {
 
     /// Select first option if multiresult
     ///
-    /// Requires: `-w/--from-where`
-    #[arg(long = "select-first", requires = "where_search")]
+    /// Requires: `-w/--from-where` or `-s/--search`
+    #[arg(long = "select-first")]
     pub select_first_option: bool,
 
     /// Generate completion script for given shell
@@ -138,6 +234,15 @@ pub struct Cli {
             "resolve_symlink",
             "no_copy",
             "no_color",
+            "ls_colors",
+            "search_pattern",
+            "full_path",
+            "hidden",
+            "no_ignore",
+            "max_depth",
+            "regex",
+            "glob",
+            "exec_command",
             "posix_style",
             "no_posix_style",
             "select_first_option",
@@ -165,6 +270,15 @@ pub struct Cli {
             "resolve_symlink",
             "no_copy",
             "no_color",
+            "ls_colors",
+            "search_pattern",
+            "full_path",
+            "hidden",
+            "no_ignore",
+            "max_depth",
+            "regex",
+            "glob",
+            "exec_command",
             "posix_style",
             "no_posix_style",
             "select_first_option",
@@ -5,6 +5,45 @@ pub trait ColorizeExt: Colorize {
     fn salmon(self) -> ColoredString;
     fn gray(self) -> ColoredString;
     fn orange(self) -> ColoredString;
+    /// Apply a semicolon-separated ANSI SGR code sequence, e.g. `"01;34"` from `LS_COLORS`.
+    /// Understands the extended 256-color (`38;5;N`) and truecolor (`38;2;r;g;b`)
+    /// foreground/background sequences as a single unit, not token-by-token
+    fn ansi_sgr(self, codes: &str) -> ColoredString;
+}
+
+/// Convert an xterm 256-color palette index to an approximate RGB triple
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    const BASIC: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (128, 0, 0),
+        (0, 128, 0),
+        (128, 128, 0),
+        (0, 0, 128),
+        (128, 0, 128),
+        (0, 128, 128),
+        (192, 192, 192),
+        (128, 128, 128),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (0, 0, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    match index {
+        0..=15 => BASIC[index as usize],
+        16..=231 => {
+            let index = index - 16;
+            let scale = |level: u8| if level == 0 { 0 } else { 55 + level * 40 };
+            (scale(index / 36), scale((index / 6) % 6), scale(index % 6))
+        }
+        232..=255 => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
 }
 
 // Implement the new trait for any type that implements `Colorize`
@@ -21,4 +60,61 @@ impl<T: Colorize> ColorizeExt for T {
     fn orange(self) -> ColoredString {
         self.truecolor(255, 165, 0)
     }
+    fn ansi_sgr(self, codes: &str) -> ColoredString {
+        let mut styled = self.normal();
+        let mut tokens = codes.split(';');
+
+        while let Some(code) = tokens.next() {
+            styled = match code {
+                "01" | "1" => styled.bold(),
+                "04" | "4" => styled.underline(),
+                "30" => styled.black(),
+                "31" => styled.red(),
+                "32" => styled.green(),
+                "33" => styled.yellow(),
+                "34" => styled.blue(),
+                "35" => styled.magenta(),
+                "36" => styled.cyan(),
+                "37" => styled.white(),
+                "38" | "48" => {
+                    let is_background = code == "48";
+                    match tokens.next() {
+                        Some("5") => match tokens.next().and_then(|index| index.parse().ok()) {
+                            Some(index) => {
+                                let (r, g, b) = ansi256_to_rgb(index);
+                                if is_background {
+                                    styled.on_truecolor(r, g, b)
+                                } else {
+                                    styled.truecolor(r, g, b)
+                                }
+                            }
+                            None => styled,
+                        },
+                        Some("2") => {
+                            let rgb = (tokens.next(), tokens.next(), tokens.next());
+                            match rgb {
+                                (Some(r), Some(g), Some(b)) => {
+                                    match (r.parse(), g.parse(), b.parse()) {
+                                        (Ok(r), Ok(g), Ok(b)) => {
+                                            if is_background {
+                                                styled.on_truecolor(r, g, b)
+                                            } else {
+                                                styled.truecolor(r, g, b)
+                                            }
+                                        }
+                                        _ => styled,
+                                    }
+                                }
+                                _ => styled,
+                            }
+                        }
+                        _ => styled,
+                    }
+                }
+                _ => styled,
+            };
+        }
+
+        styled
+    }
 }
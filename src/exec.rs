@@ -0,0 +1,46 @@
+use std::path::Path;
+use std::process::{Command, ExitCode};
+
+/// Expand fd-style placeholder tokens (`{}`, `{/}`, `{//}`, `{.}`) in `arg` against `path`
+fn expand_arg(arg: &str, path: &Path) -> String {
+    let full = path.display().to_string();
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let parent = path
+        .parent()
+        .map(|parent| parent.display().to_string())
+        .unwrap_or_default();
+    let stem = path.with_extension("").display().to_string();
+
+    arg.replace("{//}", &parent)
+        .replace("{/}", &name)
+        .replace("{.}", &stem)
+        .replace("{}", &full)
+}
+
+/// Run `command` with placeholder tokens expanded against `path`,
+/// implicitly appending `{}` when `command` contains no placeholder
+pub fn run(command: &[String], path: &Path) -> ExitCode {
+    let has_placeholder = command
+        .iter()
+        .any(|arg| ["{}", "{/}", "{//}", "{.}"].iter().any(|token| arg.contains(token)));
+
+    let mut args: Vec<String> = command.iter().map(|arg| expand_arg(arg, path)).collect();
+    if !has_placeholder {
+        args.push(path.display().to_string());
+    }
+
+    let Some((program, rest)) = args.split_first() else {
+        return ExitCode::FAILURE;
+    };
+
+    match Command::new(program).args(rest).status() {
+        Ok(status) => match status.code() {
+            Some(code) => ExitCode::from(code as u8),
+            None => ExitCode::FAILURE,
+        },
+        Err(_) => ExitCode::FAILURE,
+    }
+}
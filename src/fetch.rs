@@ -1,39 +1,165 @@
 use core::str;
-use std::process::{Command, ExitCode};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
 
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use glob::Pattern;
 use inquire::Select;
+use regex::Regex;
 
-pub fn string_path_from_search(
-    program: &str,
-    select_first_option: &bool,
-) -> Result<String, ExitCode> {
-    let output = if cfg!(target_os = "windows") {
-        Command::new("cmd")
-            .arg("/C")
-            .arg(format!("where {}", program))
-            .output()
-            .expect("'where' command found path to program/script on Windows")
-    } else {
-        todo!("implement for Linux")
+/// How the program name/pattern given to `-w/--from-where` should be matched
+/// against candidate executables on `PATH`
+pub enum MatchMode<'a> {
+    /// Match the program name exactly
+    Exact(&'a str),
+    /// Match the file stem of each candidate against a glob pattern
+    Glob(&'a str),
+    /// Match the file stem of each candidate against a regex
+    Regex(&'a str),
+}
+
+/// A `-w/--from-where` search failed to run to completion
+pub enum SearchError {
+    /// The `--glob`/`--regex` pattern could not be compiled
+    InvalidPattern(String),
+    /// The user skipped the selection prompt
+    Cancelled,
+}
+
+/// Check whether `path` is a file with at least one executable permission bit set
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    let Ok(metadata) = path.metadata() else {
+        return false;
+    };
+    metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+}
+
+/// On Windows, a `PATHEXT` match is treated as executable regardless of permission bits
+#[cfg(windows)]
+fn is_executable(path: &Path) -> bool {
+    let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+        return false;
+    };
+    let pathext =
+        env::var_os("PATHEXT").unwrap_or_else(|| std::ffi::OsString::from(".COM;.EXE;.BAT;.CMD"));
+    let is_pathext_match = env::split_paths(&pathext).any(|known| {
+        known
+            .to_str()
+            .is_some_and(|known| known.trim_start_matches('.').eq_ignore_ascii_case(ext))
+    });
+    is_pathext_match && path.is_file()
+}
+
+/// Build the candidate paths for an exact `program` name inside `dir`,
+/// expanding `PATHEXT` suffixes when `program` has no extension
+#[cfg(windows)]
+fn exact_candidates_in_dir(dir: &Path, program: &str) -> Vec<PathBuf> {
+    use std::ffi::OsString;
+
+    let direct = dir.join(program);
+    if direct.extension().is_some() {
+        return vec![direct];
+    }
+
+    let pathext = env::var_os("PATHEXT").unwrap_or_else(|| OsString::from(".COM;.EXE;.BAT;.CMD"));
+    env::split_paths(&pathext)
+        .map(|ext| {
+            let mut name = OsString::from(program);
+            name.push(ext);
+            dir.join(name)
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+fn exact_candidates_in_dir(dir: &Path, program: &str) -> Vec<PathBuf> {
+    vec![dir.join(program)]
+}
+
+/// List every entry in `dir` whose file stem satisfies `predicate`
+fn candidates_matching(dir: &Path, predicate: &dyn Fn(&str) -> bool) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
     };
-    let text = str::from_utf8(&output.stdout)
-        .expect("path string is valid UTF-8")
-        .trim()
-        .replace("\r", "")
-        .leak();
-    if text.contains("\n") {
-        let options: Vec<&str> = text.split("\n").collect();
-        if select_first_option.to_owned() {
-            return Ok(options[0].to_owned());
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+            predicate(stem)
+        })
+        .collect()
+}
+
+/// Resolve a `MatchMode` to every matching executable found on `PATH`,
+/// preserving `PATH` order and skipping paths that resolve to an already-seen file
+fn resolve_on_path(match_mode: &MatchMode) -> Result<Vec<String>, SearchError> {
+    let predicate: Box<dyn Fn(&str) -> bool> = match match_mode {
+        MatchMode::Exact(_) => Box::new(|_: &str| true),
+        MatchMode::Glob(pattern) => {
+            let glob_pattern = Pattern::new(pattern)
+                .map_err(|error| SearchError::InvalidPattern(error.to_string()))?;
+            Box::new(move |stem: &str| glob_pattern.matches(stem))
+        }
+        MatchMode::Regex(pattern) => {
+            let regex = Regex::new(pattern)
+                .map_err(|error| SearchError::InvalidPattern(error.to_string()))?;
+            Box::new(move |stem: &str| regex.is_match(stem))
         }
-        let select = Select::new("Select a path:", options);
-        return match select.prompt_skippable() {
-            Ok(answer) => match answer {
-                Some(str_answer) => Ok(str_answer.to_owned()),
-                None => return Err(ExitCode::FAILURE),
-            },
-            Err(_) => return Err(ExitCode::FAILURE),
+    };
+
+    let mut results = Vec::new();
+    let mut seen = Vec::new();
+
+    let Some(path_var) = env::var_os("PATH") else {
+        return Ok(results);
+    };
+
+    for dir in env::split_paths(&path_var) {
+        let candidates = match match_mode {
+            MatchMode::Exact(program) => exact_candidates_in_dir(&dir, program),
+            MatchMode::Glob(_) | MatchMode::Regex(_) => candidates_matching(&dir, &predicate),
         };
+        for candidate in candidates {
+            if !is_executable(&candidate) {
+                continue;
+            }
+            let resolved = candidate.canonicalize().unwrap_or_else(|_| candidate.clone());
+            if seen.contains(&resolved) {
+                continue;
+            }
+            seen.push(resolved);
+            results.push(candidate.display().to_string());
+        }
+    }
+
+    Ok(results)
+}
+
+pub fn string_path_from_search(
+    match_mode: MatchMode,
+    select_first_option: &bool,
+) -> Result<String, SearchError> {
+    let options = resolve_on_path(&match_mode)?;
+
+    if options.is_empty() {
+        return Ok(String::new());
+    }
+
+    if options.len() == 1 || select_first_option.to_owned() {
+        return Ok(options[0].to_owned());
+    }
+
+    let select = Select::new("Select a path:", options);
+    match select.prompt_skippable() {
+        Ok(answer) => match answer {
+            Some(str_answer) => Ok(str_answer),
+            None => Err(SearchError::Cancelled),
+        },
+        Err(_) => Err(SearchError::Cancelled),
     }
-    Ok(text.to_owned())
 }
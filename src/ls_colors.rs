@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::{Component, Path, PathBuf};
+
+use colored::ColoredString;
+
+use crate::colorize_ext::ColorizeExt;
+
+/// Default ANSI codes, used when `LS_COLORS` is unset
+const DEFAULT_LS_COLORS: &str = "di=01;34:ln=01;36:ex=01;32:fi=00:or=01;31:pi=33:so=01;35:bd=01;33:cd=01;33";
+
+/// File category, matched against the two-letter `LS_COLORS` keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    Directory,
+    Symlink,
+    Executable,
+    File,
+    OrphanSymlink,
+    Fifo,
+    Socket,
+    BlockDevice,
+    CharDevice,
+}
+
+impl Category {
+    fn key(self) -> &'static str {
+        match self {
+            Category::Directory => "di",
+            Category::Symlink => "ln",
+            Category::Executable => "ex",
+            Category::File => "fi",
+            Category::OrphanSymlink => "or",
+            Category::Fifo => "pi",
+            Category::Socket => "so",
+            Category::BlockDevice => "bd",
+            Category::CharDevice => "cd",
+        }
+    }
+}
+
+/// Parsed `LS_COLORS` ANSI codes, keyed by category and by filename extension
+pub struct LsColors {
+    categories: HashMap<&'static str, String>,
+    extensions: HashMap<String, String>,
+}
+
+impl LsColors {
+    /// Load `LS_COLORS` from the environment, falling back to a bundled default palette
+    pub fn load() -> Self {
+        let raw = env::var("LS_COLORS").unwrap_or_else(|_| DEFAULT_LS_COLORS.to_owned());
+        Self::parse(&raw)
+    }
+
+    fn parse(raw: &str) -> Self {
+        let mut categories = HashMap::new();
+        let mut extensions = HashMap::new();
+
+        for entry in raw.split(':') {
+            let Some((key, codes)) = entry.split_once('=') else {
+                continue;
+            };
+            if let Some(ext) = key.strip_prefix("*.") {
+                extensions.insert(ext.to_lowercase(), codes.to_owned());
+            } else if let Some(canonical) = [
+                Category::Directory,
+                Category::Symlink,
+                Category::Executable,
+                Category::File,
+                Category::OrphanSymlink,
+                Category::Fifo,
+                Category::Socket,
+                Category::BlockDevice,
+                Category::CharDevice,
+            ]
+            .iter()
+            .find(|category| category.key() == key)
+            {
+                categories.insert(canonical.key(), codes.to_owned());
+            }
+        }
+
+        Self {
+            categories,
+            extensions,
+        }
+    }
+
+    /// Style `text` using the color rule for the given filesystem path component
+    pub fn style(&self, text: &str, path: &Path, is_last: bool) -> ColoredString {
+        if is_last {
+            if let Some(ext) = path.extension().and_then(|ext| ext.to_str()) {
+                if let Some(codes) = self.extensions.get(&ext.to_lowercase()) {
+                    return text.ansi_sgr(codes);
+                }
+            }
+            return text.ansi_sgr(self.codes_for_category(self.category_for(path)));
+        }
+        text.ansi_sgr(self.codes_for_category(Category::Directory))
+    }
+
+    fn codes_for_category(&self, category: Category) -> &str {
+        self.categories
+            .get(category.key())
+            .map(String::as_str)
+            .unwrap_or("00")
+    }
+
+    fn category_for(&self, path: &Path) -> Category {
+        if path.is_symlink() {
+            return if path.exists() {
+                Category::Symlink
+            } else {
+                Category::OrphanSymlink
+            };
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::FileTypeExt;
+            if let Ok(metadata) = path.symlink_metadata() {
+                let file_type = metadata.file_type();
+                if file_type.is_fifo() {
+                    return Category::Fifo;
+                }
+                if file_type.is_socket() {
+                    return Category::Socket;
+                }
+                if file_type.is_block_device() {
+                    return Category::BlockDevice;
+                }
+                if file_type.is_char_device() {
+                    return Category::CharDevice;
+                }
+            }
+        }
+
+        if path.is_dir() {
+            return Category::Directory;
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if let Ok(metadata) = path.metadata() {
+                if metadata.is_file() && metadata.permissions().mode() & 0o111 != 0 {
+                    return Category::Executable;
+                }
+            }
+        }
+
+        Category::File
+    }
+}
+
+/// Style a displayed path component-by-component according to `LS_COLORS`
+///
+/// Walks `path.components()` directly (rather than re-splitting a display
+/// string) so an absolute path keeps its root while each component is
+/// stat'ed with its full, correctly-rooted prefix
+pub fn colorize_path(path: &Path) -> String {
+    let ls_colors = LsColors::load();
+    let components: Vec<Component> = path.components().collect();
+    let separator = if path.to_string_lossy().contains('\\') {
+        "\\"
+    } else {
+        "/"
+    };
+
+    let mut rebuilt = PathBuf::new();
+    let mut result = String::new();
+
+    for (index, component) in components.iter().enumerate() {
+        rebuilt.push(component.as_os_str());
+
+        let previous_is_root = index > 0
+            && matches!(
+                components[index - 1],
+                Component::RootDir | Component::Prefix(_)
+            );
+        if index > 0 && !previous_is_root {
+            result.push_str(separator);
+        }
+
+        let text = component.as_os_str().to_string_lossy();
+        let is_last = index + 1 == components.len();
+        result.push_str(&ls_colors.style(&text, &rebuilt, is_last).to_string());
+    }
+
+    result
+}
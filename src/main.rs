@@ -20,6 +20,29 @@ use colorize_ext::ColorizeExt;
 
 mod fetch;
 
+mod ls_colors;
+
+mod walk;
+
+mod exec;
+
+/// Print the styled `[Error]` for a flag that requires `-s/--search` or `-w/--from-where`,
+/// and return the exit code `main` should bail out with
+fn require_search_mode(flag: &str, no_color: bool) -> ExitCode {
+    if no_color {
+        println!("[Error] {flag} requires -s/--search or -w/--from-where")
+    } else {
+        let label = "[Error]".crimson();
+        let arg = flag.white();
+        let msg1 = "requires".gray();
+        let arg2 = "-s/--search".white();
+        let msg2 = "or".gray();
+        let arg3 = "-w/--from-where".white();
+        println!("{label} {arg} {msg1} {arg2} {msg2} {arg3}");
+    }
+    ExitCode::FAILURE
+}
+
 fn main() -> ExitCode {
     let args = Cli::parse();
 
@@ -40,10 +63,27 @@ fn main() -> ExitCode {
         return ExitCode::SUCCESS;
     }
 
+    // `--regex` and `--select-first` only make sense alongside a search mode;
+    // clap's `requires` can't express an OR-relationship, so check here
+    let in_search_mode = args.where_search || args.search_pattern.is_some();
+    if args.regex && !in_search_mode {
+        return require_search_mode("--regex", args.no_color);
+    }
+    if args.select_first_option && !in_search_mode {
+        return require_search_mode("--select-first", args.no_color);
+    }
+
     // Select where to extract the path from
     let mut path = if args.where_search {
         if let Some(search_name) = args.path_segment_or_program_search {
-            match fetch::string_path_from_search(&search_name, &args.select_first_option) {
+            let match_mode = if args.glob {
+                fetch::MatchMode::Glob(&search_name)
+            } else if args.regex {
+                fetch::MatchMode::Regex(&search_name)
+            } else {
+                fetch::MatchMode::Exact(&search_name)
+            };
+            match fetch::string_path_from_search(match_mode, &args.select_first_option) {
                 Ok(string_path) => {
                     if string_path.is_empty() {
                         if args.no_color {
@@ -59,7 +99,18 @@ fn main() -> ExitCode {
                         PathBuf::from(string_path)
                     }
                 }
-                Err(exit_code) => return exit_code,
+                Err(fetch::SearchError::InvalidPattern(message)) => {
+                    if args.no_color {
+                        println!("[Error] Invalid pattern \"{search_name}\": {message}");
+                    } else {
+                        let label = "[Error]".crimson();
+                        let msg = "Invalid pattern".gray();
+                        let value = format!("\"{}\": {}", search_name, message).white();
+                        println!("{label} {msg} {value}");
+                    }
+                    return ExitCode::FAILURE;
+                }
+                Err(fetch::SearchError::Cancelled) => return ExitCode::FAILURE,
             }
         } else {
             if args.no_color {
@@ -74,8 +125,51 @@ fn main() -> ExitCode {
             }
             return ExitCode::FAILURE;
         }
+    } else if let Some(pattern) = args.search_pattern {
+        let segment = args
+            .path_segment_or_program_search
+            .unwrap_or(".".to_owned());
+        let root = current_dir()
+            .expect("cwd was found and have permission")
+            .join(segment);
+        let options = walk::SearchOptions {
+            full_path: args.full_path,
+            hidden: args.hidden,
+            no_ignore: args.no_ignore,
+            max_depth: args.max_depth,
+            regex_match: args.regex,
+        };
+        let matches = match walk::find_matches(&root, &pattern, &options) {
+            Ok(matches) => matches,
+            Err(error) => {
+                if args.no_color {
+                    println!("[Error] Invalid regex \"{pattern}\": {error}");
+                } else {
+                    let label = "[Error]".crimson();
+                    let msg = "Invalid regex".gray();
+                    let value = format!("\"{}\": {}", pattern, error).white();
+                    println!("{label} {msg} {value}");
+                }
+                return ExitCode::FAILURE;
+            }
+        };
+        match walk::select_match(matches, &args.select_first_option) {
+            Ok(Some(found_path)) => found_path,
+            Ok(None) => {
+                if args.no_color {
+                    println!("[Error] Could not find \"{pattern}\"");
+                } else {
+                    let label = "[Error]".crimson();
+                    let msg = "Could not find".gray();
+                    let value = format!("\"{}\"", pattern).white();
+                    println!("{label} {msg} {value}");
+                }
+                return ExitCode::FAILURE;
+            }
+            Err(exit_code) => return exit_code,
+        }
     } else {
-        // If not using `-w/--from-where`, use current working directory
+        // If not using `-w/--from-where` or `-s/--search`, use current working directory
         let segment = args
             .path_segment_or_program_search
             .unwrap_or(".".to_owned());
@@ -124,6 +218,10 @@ fn main() -> ExitCode {
             .to_path_buf()
     }
 
+    // Colorize the real path by file type before it is turned into display text,
+    // so stat'ing each component sees a correctly-rooted prefix
+    let colored_path = (!args.no_color && args.ls_colors).then(|| ls_colors::colorize_path(&path));
+
     // Apply styling options
     let mut visual = path.display().to_string();
 
@@ -141,6 +239,21 @@ fn main() -> ExitCode {
         visual = visual.replace("\\", "\\\\")
     }
 
+    let colored_visual = colored_path.map(|mut colored| {
+        if args.posix_style {
+            colored = colored.replace("\\", "/")
+        } else if args.no_posix_style {
+            colored = colored.replace("/", "\\")
+        }
+        if args.wrap_quote {
+            colored = format!("\"{}\"", colored)
+        }
+        if args.escape_backslash {
+            colored = colored.replace("\\", "\\\\")
+        }
+        colored
+    });
+
     // Final actions
     if !args.no_copy {
         cli_clipboard::set_contents(visual.to_owned()).expect("clipboard opened successfully");
@@ -148,6 +261,8 @@ fn main() -> ExitCode {
 
     if args.no_color {
         println!("{}", visual);
+    } else if let Some(colored_visual) = colored_visual {
+        println!("{}", colored_visual);
     } else {
         println!("{}", visual.salmon());
     }
@@ -160,5 +275,9 @@ fn main() -> ExitCode {
         let _ = keyboard.key(Key::Return, enigo::Direction::Press);
     }
 
+    if let Some(command) = args.exec_command {
+        return exec::run(&command, &path);
+    }
+
     ExitCode::SUCCESS
 }
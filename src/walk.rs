@@ -0,0 +1,92 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use ignore::WalkBuilder;
+use inquire::Select;
+use regex::Regex;
+
+/// Options controlling how `find_matches` walks the directory tree
+pub struct SearchOptions {
+    pub full_path: bool,
+    pub hidden: bool,
+    pub no_ignore: bool,
+    pub max_depth: Option<usize>,
+    pub regex_match: bool,
+}
+
+/// Walk `root`, collecting every entry whose name (or relative path,
+/// with `full_path`) matches `pattern` as a substring, or as a regex
+/// when `regex_match` is set
+pub fn find_matches(
+    root: &Path,
+    pattern: &str,
+    options: &SearchOptions,
+) -> Result<Vec<PathBuf>, regex::Error> {
+    let matcher: Box<dyn Fn(&str) -> bool> = if options.regex_match {
+        let regex = Regex::new(pattern)?;
+        Box::new(move |candidate: &str| regex.is_match(candidate))
+    } else {
+        let pattern = pattern.to_owned();
+        Box::new(move |candidate: &str| candidate.contains(&pattern))
+    };
+
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .hidden(!options.hidden)
+        .git_ignore(!options.no_ignore)
+        .ignore(!options.no_ignore);
+    if let Some(max_depth) = options.max_depth {
+        builder.max_depth(Some(max_depth));
+    }
+
+    let mut matches = Vec::new();
+    for entry in builder.build().flatten() {
+        let path = entry.path();
+        if path == root {
+            continue;
+        }
+
+        let candidate = if options.full_path {
+            path.strip_prefix(root)
+                .unwrap_or(path)
+                .display()
+                .to_string()
+        } else {
+            entry.file_name().to_string_lossy().to_string()
+        };
+
+        if matcher(&candidate) {
+            matches.push(path.to_path_buf());
+        }
+    }
+
+    Ok(matches)
+}
+
+/// Prompt the user to pick one of several matching paths,
+/// mirroring `fetch::string_path_from_search`'s selection flow
+pub fn select_match(
+    matches: Vec<PathBuf>,
+    select_first_option: &bool,
+) -> Result<Option<PathBuf>, ExitCode> {
+    if matches.is_empty() {
+        return Ok(None);
+    }
+
+    if matches.len() == 1 || select_first_option.to_owned() {
+        return Ok(Some(matches[0].to_owned()));
+    }
+
+    let options: Vec<String> = matches
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect();
+    let select = Select::new("Select a path:", options);
+    match select.prompt_skippable() {
+        Ok(answer) => match answer {
+            Some(str_answer) => Ok(Some(PathBuf::from(str_answer))),
+            None => Err(ExitCode::FAILURE),
+        },
+        Err(_) => Err(ExitCode::FAILURE),
+    }
+}